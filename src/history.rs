@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+use serde_wasm_bindgen::{from_value, to_value as to_js_value};
+use wasm_bindgen::prelude::*;
+
+/// A single completed game, kept so the JS front-end can replay it or
+/// derive stats without tracking state of its own.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameRecord {
+    pub secret: u32,
+    pub guesses: Vec<u32>,
+    pub attempts: u32,
+    pub won: bool,
+}
+
+/// Aggregate win/attempt stats derived from a `History`.
+#[derive(Serialize)]
+pub struct Stats {
+    pub games_played: u32,
+    pub wins: u32,
+    pub win_rate: f64,
+    pub average_attempts: f64,
+    pub best_attempts: Option<u32>,
+}
+
+/// Every completed game played across `reset` calls, in play order.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct History {
+    pub games: Vec<GameRecord>,
+}
+
+impl History {
+    pub fn record(&mut self, secret: u32, guesses: Vec<u32>, attempts: u32, won: bool) {
+        self.games.push(GameRecord { secret, guesses, attempts, won });
+    }
+
+    pub fn clear(&mut self) {
+        self.games.clear();
+    }
+
+    pub fn stats(&self) -> Stats {
+        let games_played = self.games.len() as u32;
+        let wins = self.games.iter().filter(|g| g.won).count() as u32;
+        let average_attempts = if games_played == 0 {
+            0.0
+        } else {
+            self.games.iter().map(|g| g.attempts as f64).sum::<f64>() / games_played as f64
+        };
+        let best_attempts = self.games.iter().filter(|g| g.won).map(|g| g.attempts).min();
+        let win_rate = if games_played == 0 {
+            0.0
+        } else {
+            wins as f64 / games_played as f64
+        };
+        Stats { games_played, wins, win_rate, average_attempts, best_attempts }
+    }
+
+    pub fn to_js(&self) -> JsValue {
+        to_js_value(self).expect("serialize History")
+    }
+
+    pub fn from_js(value: JsValue) -> Result<History, JsValue> {
+        from_value(value).map_err(|err| JsValue::from_str(&err.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_on_empty_history_has_no_best() {
+        let history = History::default();
+        let stats = history.stats();
+        assert_eq!(stats.games_played, 0);
+        assert_eq!(stats.best_attempts, None);
+    }
+
+    #[test]
+    fn stats_track_wins_and_averages() {
+        let mut history = History::default();
+        history.record(28, vec![50, 12, 28], 3, true);
+        history.record(7, vec![50, 25, 10], 3, false);
+
+        let stats = history.stats();
+        assert_eq!(stats.games_played, 2);
+        assert_eq!(stats.wins, 1);
+        assert_eq!(stats.best_attempts, Some(3));
+        assert!((stats.average_attempts - 3.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn best_attempts_ignores_losses_even_when_faster() {
+        let mut history = History::default();
+        history.record(7, vec![5], 1, false);
+        history.record(28, vec![50, 12, 28], 3, true);
+
+        let stats = history.stats();
+        assert_eq!(stats.best_attempts, Some(3));
+    }
+}
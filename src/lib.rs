@@ -1,82 +1,278 @@
+mod history;
+mod solver;
+
 use wasm_bindgen::prelude::*;
 use rand::Rng;
 use serde::Serialize;
 use serde_wasm_bindgen::to_value as to_js_value;
 
-fn generate_secret(max: u32) -> u32 {
+use history::History;
+pub use solver::Solver;
+
+pub(crate) fn generate_secret(min: u32, max: u32) -> u32 {
     let mut rng = rand::thread_rng();
-    rng.gen_range(1..=max)
+    rng.gen_range(min..=max)
+}
+
+/// Buckets how close `value` is to `secret` relative to the `min..=max`
+/// range width into a "you're getting warmer" band.
+fn proximity_band(value: u32, secret: u32, min: u32, max: u32) -> &'static str {
+    let range_width = (max - min).max(1);
+    let distance = value.abs_diff(secret);
+    let ratio = distance as f64 / range_width as f64;
+
+    if ratio <= 0.05 {
+        "hot"
+    } else if ratio <= 0.15 {
+        "warm"
+    } else if ratio <= 0.35 {
+        "cold"
+    } else {
+        "freezing"
+    }
+}
+
+fn gcd(a: u32, b: u32) -> u32 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// The lifecycle of a single game: still guessable, won, or lost by
+/// running out of attempts.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GameStatus {
+    InProgress,
+    Won,
+    Lost,
 }
 
 #[derive(Serialize)]
 struct GuessResponse<'a> {
     result: &'a str, // "low" | "high" | "correct"
     attempts: u32,
+    status: GameStatus,
+    remaining_attempts: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    secret: Option<u32>,
+    proximity: &'a str, // "freezing" | "cold" | "warm" | "hot"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sum: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    gcd: Option<u32>,
+}
+
+/// Recoverable errors surfaced to JS as structured, catchable values
+/// rather than thrown strings.
+#[derive(Serialize)]
+#[serde(tag = "error", rename_all = "snake_case")]
+enum GameError {
+    OutOfRange { min: u32, max: u32 },
+    GameFinished { status: GameStatus },
+}
+
+impl GameError {
+    fn into_js(self) -> JsValue {
+        to_js_value(&self).expect("serialize GameError")
+    }
+}
+
+/// A guess that has already been validated against a `min..=max` range.
+/// Centralizes the bounds check so `Game::guess` and the solver share it.
+struct Guess(u32);
+
+impl Guess {
+    fn new(value: u32, min: u32, max: u32) -> Result<Guess, GameError> {
+        if value < min || value > max {
+            return Err(GameError::OutOfRange { min, max });
+        }
+        Ok(Guess(value))
+    }
+
+    fn value(&self) -> u32 {
+        self.0
+    }
 }
 
 #[wasm_bindgen]
 pub struct Game {
     secret: u32,
+    min: u32,
     max: u32,
     attempts: u32,
-    finished: bool,
+    max_attempts: Option<u32>,
+    status: GameStatus,
+    guesses: Vec<u32>,
+    history: History,
+    hints_enabled: bool,
 }
 
 #[wasm_bindgen]
 impl Game {
     #[wasm_bindgen(constructor)]
-    pub fn new(max: Option<u32>) -> Game {
-        let max_bound = max.unwrap_or(100).max(1);
+    pub fn new(
+        min: Option<u32>,
+        max: Option<u32>,
+        max_attempts: Option<u32>,
+        hints: Option<bool>,
+    ) -> Game {
+        let min_bound = min.unwrap_or(1);
+        let max_bound = max.unwrap_or(100);
+        if min_bound > max_bound {
+            wasm_bindgen::throw_str("min must be less than or equal to max");
+        }
         Game {
-            secret: generate_secret(max_bound),
+            secret: generate_secret(min_bound, max_bound),
+            min: min_bound,
             max: max_bound,
             attempts: 0,
-            finished: false,
+            max_attempts,
+            status: GameStatus::InProgress,
+            guesses: Vec::new(),
+            history: History::default(),
+            hints_enabled: hints.unwrap_or(false),
         }
     }
 
-    /// Resets the game. If a new max is provided, updates the range.
-    pub fn reset(&mut self, max: Option<u32>) {
+    /// Resets the game. If a new min/max, max_attempts, or hints flag is
+    /// provided, updates them.
+    pub fn reset(
+        &mut self,
+        min: Option<u32>,
+        max: Option<u32>,
+        max_attempts: Option<u32>,
+        hints: Option<bool>,
+    ) {
+        if let Some(m) = min {
+            self.min = m;
+        }
         if let Some(m) = max {
-            self.max = m.max(1);
+            self.max = m;
+        }
+        if self.min > self.max {
+            wasm_bindgen::throw_str("min must be less than or equal to max");
+        }
+        if max_attempts.is_some() {
+            self.max_attempts = max_attempts;
+        }
+        if let Some(h) = hints {
+            self.hints_enabled = h;
         }
-        self.secret = generate_secret(self.max);
+        self.secret = generate_secret(self.min, self.max);
         self.attempts = 0;
-        self.finished = false;
+        self.status = GameStatus::InProgress;
+        self.guesses.clear();
     }
 
     /// Makes a guess and returns a structured result as a JS object.
-    /// Throws a JS exception if the guess is out of range.
-    pub fn guess(&mut self, value: u32) -> JsValue {
-        if value < 1 || value > self.max {
-            wasm_bindgen::throw_str("Guess out of range");
+    /// Rejects with a structured, catchable error if the guess is out of
+    /// range or the game has already finished.
+    pub fn guess(&mut self, value: u32) -> Result<JsValue, JsValue> {
+        if self.status != GameStatus::InProgress {
+            return Err(GameError::GameFinished { status: self.status }.into_js());
         }
 
-        if self.finished {
-            let resp = GuessResponse { result: "correct", attempts: self.attempts };
-            return to_js_value(&resp).expect("serialize GuessResponse");
-        }
+        let guess = Guess::new(value, self.min, self.max).map_err(GameError::into_js)?;
 
         self.attempts = self.attempts.saturating_add(1);
+        self.guesses.push(guess.value());
 
-        let result = if value < self.secret {
+        let result = if guess.value() < self.secret {
             "low"
-        } else if value > self.secret {
+        } else if guess.value() > self.secret {
             "high"
         } else {
-            self.finished = true;
+            self.status = GameStatus::Won;
             "correct"
         };
 
-        let resp = GuessResponse { result, attempts: self.attempts };
-        to_js_value(&resp).expect("serialize GuessResponse")
+        if self.status == GameStatus::InProgress {
+            if let Some(limit) = self.max_attempts {
+                if self.attempts >= limit {
+                    self.status = GameStatus::Lost;
+                }
+            }
+        }
+
+        if self.status != GameStatus::InProgress {
+            self.history.record(
+                self.secret,
+                self.guesses.clone(),
+                self.attempts,
+                self.status == GameStatus::Won,
+            );
+        }
+
+        let secret = if self.status == GameStatus::Lost { Some(self.secret) } else { None };
+        let proximity = proximity_band(guess.value(), self.secret, self.min, self.max);
+        let (sum, gcd) = if self.hints_enabled {
+            (
+                Some(guess.value() as u64 + self.secret as u64),
+                Some(gcd(guess.value(), self.secret)),
+            )
+        } else {
+            (None, None)
+        };
+
+        let resp = GuessResponse {
+            result,
+            attempts: self.attempts,
+            status: self.status,
+            remaining_attempts: self.get_remaining(),
+            secret,
+            proximity,
+            sum,
+            gcd,
+        };
+        Ok(to_js_value(&resp).expect("serialize GuessResponse"))
     }
 
     /// Returns the current attempt count.
     pub fn get_attempts(&self) -> u32 { self.attempts }
 
+    /// Returns the current min bound.
+    pub fn get_min(&self) -> u32 { self.min }
+
     /// Returns the current max bound.
     pub fn get_max(&self) -> u32 { self.max }
+
+    /// Returns the current game status.
+    pub fn get_status(&self) -> GameStatus { self.status }
+
+    /// Returns the number of attempts left, or `u32::MAX` if unbounded.
+    pub fn get_remaining(&self) -> u32 {
+        match self.max_attempts {
+            Some(limit) => limit.saturating_sub(self.attempts),
+            None => u32::MAX,
+        }
+    }
+
+    /// Exports the full game history as `{"games": [...]}` for a JS
+    /// front-end to persist (e.g. in localStorage).
+    pub fn export_history(&self) -> JsValue {
+        self.history.to_js()
+    }
+
+    /// Restores a previously exported history, replacing the current one.
+    pub fn import_history(&mut self, data: JsValue) -> Result<(), JsValue> {
+        self.history = History::from_js(data)?;
+        Ok(())
+    }
+
+    /// Discards all recorded game history.
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+    }
+
+    /// Returns aggregate stats (win rate, average/best attempts) over
+    /// the recorded history.
+    pub fn stats(&self) -> JsValue {
+        to_js_value(&self.history.stats()).expect("serialize Stats")
+    }
 }
 
 // Optional: basic unit tests for logic (non-WASM)
@@ -87,10 +283,45 @@ mod tests {
     #[test]
     fn secret_within_bounds() {
         for max in [1u32, 2, 10, 100, 500] {
-            let s = generate_secret(max);
+            let s = generate_secret(1, max);
             assert!(s >= 1 && s <= max);
         }
     }
+
+    #[test]
+    fn secret_within_custom_bounds() {
+        for (min, max) in [(10u32, 20u32), (50, 100), (5, 5)] {
+            let s = generate_secret(min, max);
+            assert!(s >= min && s <= max);
+        }
+    }
+
+    #[test]
+    fn guess_rejects_out_of_range() {
+        assert!(Guess::new(0, 1, 100).is_err());
+        assert!(Guess::new(101, 1, 100).is_err());
+        assert!(Guess::new(1, 1, 100).is_ok());
+        assert!(Guess::new(100, 1, 100).is_ok());
+    }
+
+    #[test]
+    fn proximity_band_gets_warmer_closer_to_secret() {
+        assert_eq!(proximity_band(50, 50, 1, 100), "hot");
+        assert_eq!(proximity_band(54, 50, 1, 100), "hot");
+        assert_eq!(proximity_band(62, 50, 1, 100), "warm");
+        assert_eq!(proximity_band(80, 50, 1, 100), "cold");
+        assert_eq!(proximity_band(1, 100, 1, 100), "freezing");
+    }
+
+    #[test]
+    fn gcd_of_non_coprime_numbers() {
+        assert_eq!(gcd(28, 12), 4);
+    }
+
+    #[test]
+    fn gcd_of_coprime_numbers_is_one() {
+        assert_eq!(gcd(7, 13), 1);
+    }
 }
 
 
@@ -0,0 +1,138 @@
+use serde::{Deserialize, Serialize};
+use serde_wasm_bindgen::{from_value as from_js_value, to_value as to_js_value};
+use wasm_bindgen::prelude::*;
+
+use crate::Game;
+
+/// Errors from a solver whose candidate interval has become inconsistent
+/// with the feedback it was given.
+#[derive(Serialize)]
+#[serde(tag = "error", rename_all = "snake_case")]
+enum SolverError {
+    EmptyInterval { lo: u32, hi: u32 },
+}
+
+impl SolverError {
+    fn into_js(self) -> JsValue {
+        to_js_value(&self).expect("serialize SolverError")
+    }
+}
+
+#[derive(Serialize)]
+struct AutoPlayResult {
+    secret: u32,
+    guesses: Vec<u32>,
+    attempts: u32,
+}
+
+/// The subset of `GuessResponse` the solver needs to update its interval.
+#[derive(Deserialize)]
+struct GuessFeedback {
+    result: String,
+}
+
+/// Binary-search hint engine: tracks the candidate interval `[lo, hi]`
+/// implied by past feedback and suggests its midpoint, which halves the
+/// search space on every guess (ceil(log2(n + 1)) worst-case guesses for
+/// `n` candidates).
+#[wasm_bindgen]
+pub struct Solver {
+    lo: u32,
+    hi: u32,
+}
+
+#[wasm_bindgen]
+impl Solver {
+    #[wasm_bindgen(constructor)]
+    pub fn new(min: u32, max: u32) -> Solver {
+        Solver { lo: min, hi: max }
+    }
+
+    /// Returns the midpoint of the current candidate interval.
+    pub fn suggest_next(&self) -> Result<u32, JsValue> {
+        self.check_interval().map_err(SolverError::into_js)?;
+        Ok(self.lo + (self.hi - self.lo) / 2)
+    }
+
+    /// Returns how many values remain consistent with the feedback so far.
+    pub fn candidates_remaining(&self) -> Result<u32, JsValue> {
+        self.check_interval().map_err(SolverError::into_js)?;
+        Ok(self.hi - self.lo + 1)
+    }
+
+    /// Narrows the interval after a "low" response: the secret is above `guess`.
+    pub fn record_low(&mut self, guess: u32) {
+        self.lo = guess.saturating_add(1);
+    }
+
+    /// Narrows the interval after a "high" response: the secret is below `guess`.
+    pub fn record_high(&mut self, guess: u32) {
+        self.hi = guess.saturating_sub(1);
+    }
+
+    /// Plays a fresh `Game` over `min..=max` to completion using this
+    /// solver's binary-search strategy, and reports the guesses it took
+    /// to find the secret.
+    pub fn auto_play(min: u32, max: u32) -> Result<JsValue, JsValue> {
+        let mut game = Game::new(Some(min), Some(max), None, None);
+        let mut solver = Solver::new(min, max);
+        let mut guesses = Vec::new();
+
+        let secret = loop {
+            let guess = solver.suggest_next()?;
+            guesses.push(guess);
+
+            let response = game.guess(guess)?;
+            let feedback: GuessFeedback =
+                from_js_value(response).map_err(|err| JsValue::from_str(&err.to_string()))?;
+
+            match feedback.result.as_str() {
+                "correct" => break guess,
+                "low" => solver.record_low(guess),
+                _ => solver.record_high(guess),
+            }
+        };
+
+        let result = AutoPlayResult { secret, attempts: guesses.len() as u32, guesses };
+        Ok(to_js_value(&result).expect("serialize AutoPlayResult"))
+    }
+
+    /// Checks the candidate interval without crossing into JS-value
+    /// territory, so host-target tests can exercise it directly.
+    fn check_interval(&self) -> Result<(), SolverError> {
+        if self.lo > self.hi {
+            return Err(SolverError::EmptyInterval { lo: self.lo, hi: self.hi });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn suggest_next_is_midpoint() {
+        let solver = Solver::new(1, 100);
+        assert_eq!(solver.suggest_next().unwrap(), 50);
+    }
+
+    #[test]
+    fn halves_search_space_each_guess() {
+        let mut solver = Solver::new(1, 100);
+        assert_eq!(solver.candidates_remaining().unwrap(), 100);
+        solver.record_low(50);
+        assert_eq!(solver.candidates_remaining().unwrap(), 50);
+    }
+
+    #[test]
+    fn detects_inconsistent_feedback() {
+        let mut solver = Solver::new(1, 10);
+        solver.record_low(8);
+        solver.record_high(5);
+        // Exercise `check_interval` directly: `suggest_next`'s error path
+        // round-trips through `serde_wasm_bindgen::to_value`, which needs
+        // a JS runtime and panics on a plain host-target `#[test]`.
+        assert!(solver.check_interval().is_err());
+    }
+}